@@ -1,5 +1,10 @@
 use crate::{AuthOpt, CommonOpt};
 use acidjson::AcidJson;
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Key, XChaCha20Poly1305, XNonce,
+};
 use geph4_binder_transport::{
     BinderClient, BinderError, BinderRequestData, BinderResponse, BridgeDescriptor, ExitDescriptor,
 };
@@ -11,46 +16,325 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sha2::Sha256;
 use smol::prelude::*;
 use smol_timeout::TimeoutExt;
-use std::{collections::BTreeMap, fmt::Debug, sync::Arc, time::Duration, time::SystemTime};
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime},
+};
 
 /// An cached client
 pub struct ClientCache {
     username: String,
     password: String,
-    binder_client: Arc<dyn BinderClient>,
+    /// All known binder frontends. `request_with_retry` rotates through these on failed
+    /// attempts, so a single down frontend doesn't stall every request.
+    binder_clients: Vec<Arc<dyn BinderClient>>,
+    next_binder_client: AtomicUsize,
+    retry_policy: RetryPolicy,
     free_pk: mizaru::PublicKey,
     plus_pk: mizaru::PublicKey,
     database: AcidJson<BTreeMap<String, Bytes>>,
+    /// Seals/opens every value before it touches `database`, so a stolen `ngcredentials.json`
+    /// is useless without `password`.
+    cipher: XChaCha20Poly1305,
+    /// `(recorded_at, ttl)` for each expanded cache key that has been read or written at least
+    /// once this run, so `spawn_refresher` knows when an entry is approaching expiry without
+    /// needing to know how to deserialize it.
+    deadlines: smol::lock::Mutex<BTreeMap<String, (u64, Duration)>>,
+    /// Per-key bookkeeping for the background refresher: coalescing concurrent refreshes and
+    /// backing off after binder errors.
+    refresh_state: smol::lock::Mutex<BTreeMap<String, RefreshState>>,
+    /// Subscribers registered via `subscribe_level_changes`.
+    level_listeners: smol::lock::Mutex<Vec<smol::channel::Sender<LevelChange>>>,
+    /// Decrypted `Token` memoized by `serve_agent` so it needn't re-derive it from disk on
+    /// every agent request; cleared once `AGENT_IDLE_LOCK` has passed since it was memoized, so
+    /// the signing material isn't kept resident in a long-lived agent process forever. Requests
+    /// served from the memo do not extend that time.
+    token_memo: smol::lock::Mutex<Option<(Token, Instant)>>,
+    metrics: CacheMetricsInner,
     pub force_sync: bool,
 }
 
+/// Hit/miss/staleness counters and per-request-kind binder latency, updated as the cache is
+/// used and read out via `ClientCache::metrics`.
+#[derive(Default)]
+struct CacheMetricsInner {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    forced_syncs: AtomicU64,
+    stale_serves: AtomicU64,
+    binder_latency: smol::lock::Mutex<BTreeMap<&'static str, LatencyStats>>,
+}
+
+/// Point-in-time snapshot of `ClientCache`'s hit/miss/staleness counters and per-request-kind
+/// binder latency, so the connecting daemon can log or export these numbers to diagnose slow
+/// startups and flaky bridges.
+#[derive(Debug, Clone, Default)]
+pub struct CacheMetrics {
+    pub hits: u64,
+    pub misses: u64,
+    pub forced_syncs: u64,
+    pub stale_serves: u64,
+    pub binder_latency: BTreeMap<&'static str, LatencyStats>,
+}
+
+/// Aggregated latency observed for one kind of binder request (e.g. `"authenticate"`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LatencyStats {
+    pub count: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.count as u32
+        }
+    }
+}
+
+/// A notification emitted by the background refresher when the authenticated user's token
+/// level changes (e.g. `plus` -> `free` after a subscription lapses).
+#[derive(Debug, Clone)]
+pub struct LevelChange {
+    pub old_level: String,
+    pub new_level: String,
+}
+
+/// Refresher bookkeeping for a single cache key.
+#[derive(Default)]
+struct RefreshState {
+    in_flight: bool,
+    backoff: Duration,
+    retry_after: Option<Instant>,
+}
+
+/// How often the background refresher wakes up to check deadlines.
+const REFRESH_POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// Fraction of an entry's TTL after which the refresher proactively re-fetches it.
+const REFRESH_TRIGGER_FRAC: f64 = 0.8;
+/// Base delay the refresher backs off by after a binder error, doubling on each consecutive
+/// failure up to `REFRESH_BACKOFF_MAX`.
+const REFRESH_BACKOFF_BASE: Duration = Duration::from_secs(5);
+const REFRESH_BACKOFF_MAX: Duration = Duration::from_secs(300);
+
+/// Cache key and TTL for the auth token, shared by `get_auth_token`, `force_refresh`, and
+/// `REFRESH_JOBS` so the refresher can never drift out of sync with actual cache behavior.
+const AUTH_TOKEN_KEY: &str = "cache.auth_token";
+const AUTH_TOKEN_TTL: Duration = Duration::from_secs(86400);
+/// Cache key and TTL for the exit list, shared the same way.
+const EXITS_KEY: &str = "cache.exits";
+const EXITS_TTL: Duration = Duration::from_secs(3600);
+/// Cache key and TTL for the free-exit list, shared the same way.
+const FREE_EXITS_KEY: &str = "cache.freeexits";
+const FREE_EXITS_TTL: Duration = Duration::from_secs(3600);
+
+/// The keys and TTLs the background refresher keeps warm. Bridges are deliberately excluded:
+/// they're per-exit and only worth refreshing once a connection actually wants them.
+const REFRESH_JOBS: &[(&str, Duration)] = &[
+    (AUTH_TOKEN_KEY, AUTH_TOKEN_TTL),
+    (EXITS_KEY, EXITS_TTL),
+    (FREE_EXITS_KEY, FREE_EXITS_TTL),
+];
+
+/// Retry policy for `request_with_retry`: how many attempts to make against the binder, the
+/// base delay between them, and the ceiling the jittered exponential backoff is capped at.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Overall wall-clock budget for all attempts (and backoff sleeps) combined, so a caller
+    /// that cannot tolerate staleness (e.g. `get_auth_token`) still fails in bounded time instead
+    /// of `max_attempts * NETWORK_TIMEOUT`.
+    pub overall_budget: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            overall_budget: Duration::from_secs(240),
+        }
+    }
+}
+
 static NETWORK_TIMEOUT: Duration = Duration::from_secs(120);
 static STALE_TIMEOUT: Duration = Duration::from_secs(3);
+/// Key under which the random Argon2id salt is stored in `database`. Deliberately outside the
+/// `{key}-{username}` namespace used by cached values, and never encrypted itself.
+const KDF_SALT_KEY: &str = "cache.kdf_salt";
+
+/// Per-cache-key schema version, bumped independently for each key when *that* key's value type
+/// changes shape in a way that breaks `bincode` compatibility with entries written by older
+/// clients. Keeping these independent (rather than one version for the whole cache) means
+/// bumping `Token`'s version doesn't also evict unrelated cached exit/free-exit/bridge lists. An
+/// entry whose `schema_version` doesn't match its key's current version is treated as absent
+/// rather than causing a deserialization panic.
+const AUTH_TOKEN_SCHEMA_VERSION: u16 = 1;
+const EXITS_SCHEMA_VERSION: u16 = 1;
+const FREE_EXITS_SCHEMA_VERSION: u16 = 1;
+const BRIDGES_SCHEMA_VERSION: u16 = 1;
+/// Fallback version for any cache key not listed above (currently none; kept for forward
+/// compatibility with keys added later).
+const DEFAULT_SCHEMA_VERSION: u16 = 1;
+
+/// Looks up the schema version to seal/require for the *unexpanded* cache key (e.g.
+/// `AUTH_TOKEN_KEY`, not `to_key(AUTH_TOKEN_KEY)`).
+fn schema_version_for(key: &str) -> u16 {
+    match key {
+        AUTH_TOKEN_KEY => AUTH_TOKEN_SCHEMA_VERSION,
+        EXITS_KEY => EXITS_SCHEMA_VERSION,
+        FREE_EXITS_KEY => FREE_EXITS_SCHEMA_VERSION,
+        _ if key.starts_with("cache.bridges.") => BRIDGES_SCHEMA_VERSION,
+        _ => DEFAULT_SCHEMA_VERSION,
+    }
+}
+
+/// On-disk envelope wrapping every cached value, so a format change can be detected and
+/// gracefully ignored instead of panicking on `bincode::deserialize(..).unwrap()`.
+#[derive(Serialize, Deserialize)]
+struct CacheEnvelope {
+    schema_version: u16,
+    payload: Bytes,
+}
+
+/// Derives a 32-byte symmetric key from the user's password with Argon2id.
+fn derive_cache_key(password: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .expect("argon2id key derivation failed");
+    key
+}
+
+/// Wraps `value` in a `CacheEnvelope` versioned per `key` (see `schema_version_for`) and seals it
+/// with `cipher`: a random 24-byte nonce followed by the XChaCha20-Poly1305 ciphertext. Free
+/// function (rather than a `ClientCache` method) so it's testable without constructing a whole
+/// `ClientCache`.
+fn seal_with<T: Serialize>(cipher: &XChaCha20Poly1305, key: &str, value: &T) -> Bytes {
+    let envelope = CacheEnvelope {
+        schema_version: schema_version_for(key),
+        payload: bincode::serialize(value)
+            .expect("bincode serialization cannot fail")
+            .into(),
+    };
+    let plaintext = bincode::serialize(&envelope).expect("bincode serialization cannot fail");
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let mut ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_ref())
+        .expect("encryption failed");
+    let mut sealed = nonce.to_vec();
+    sealed.append(&mut ciphertext);
+    sealed.into()
+}
+
+/// Opens a value sealed by `seal_with` for the same `key`. Any failure — truncated data, a wrong
+/// key, a corrupt auth tag, or a `schema_version` that doesn't match `key`'s current version —
+/// yields `None` rather than panicking.
+fn unseal_with<T: DeserializeOwned>(cipher: &XChaCha20Poly1305, key: &str, sealed: &[u8]) -> Option<T> {
+    if sealed.len() < 24 {
+        return None;
+    }
+    let (nonce, ciphertext) = sealed.split_at(24);
+    let plaintext = cipher.decrypt(XNonce::from_slice(nonce), ciphertext).ok()?;
+    let envelope: CacheEnvelope = bincode::deserialize(&plaintext).ok()?;
+    if envelope.schema_version != schema_version_for(key) {
+        return None;
+    }
+    bincode::deserialize(&envelope.payload).ok()
+}
+
+/// Doubles `delay` for the next retry attempt, capped at `max_delay`. Pulled out of
+/// `request_with_retry`'s loop so the doubling-and-capping behavior can be tested without a
+/// fake `BinderClient`.
+fn next_delay(delay: Duration, max_delay: Duration) -> Duration {
+    (delay * 2).min(max_delay)
+}
 
 impl ClientCache {
-    /// Create a new ClientCache that saves to the given database.
+    /// Create a new ClientCache that saves to the given database, talking to `binder_clients` in
+    /// round-robin order on retry (a single frontend is just a `vec![...]` of length one).
     pub fn new(
         username: &str,
         password: &str,
         free_pk: mizaru::PublicKey,
         plus_pk: mizaru::PublicKey,
-        binder_client: Arc<dyn BinderClient>,
+        binder_clients: Vec<Arc<dyn BinderClient>>,
         database: AcidJson<BTreeMap<String, Bytes>>,
     ) -> Self {
+        assert!(
+            !binder_clients.is_empty(),
+            "ClientCache needs at least one binder frontend"
+        );
+        let salt = {
+            let mut db = database.write();
+            let existing_valid = db
+                .get(KDF_SALT_KEY)
+                .filter(|existing| existing.len() == 16)
+                .map(|existing| {
+                    let mut salt = [0u8; 16];
+                    salt.copy_from_slice(existing);
+                    salt
+                });
+            match existing_valid {
+                Some(salt) => salt,
+                None => {
+                    // Missing, or a `cache.kdf_salt` of the wrong length (a corrupted file, a
+                    // disk error, or a foreign value from some other client version): generate a
+                    // fresh salt rather than panicking. This invalidates the existing cache, the
+                    // same as a password change would.
+                    let mut salt = [0u8; 16];
+                    rand::thread_rng().fill_bytes(&mut salt);
+                    db.insert(KDF_SALT_KEY.to_string(), Bytes::copy_from_slice(&salt));
+                    salt
+                }
+            }
+        };
+        let cipher = XChaCha20Poly1305::new(Key::from_slice(&derive_cache_key(password, &salt)));
         ClientCache {
             username: username.to_string(),
             password: password.to_string(),
-            binder_client,
+            binder_clients,
+            next_binder_client: AtomicUsize::new(0),
+            retry_policy: RetryPolicy::default(),
             free_pk,
             plus_pk,
             database,
+            cipher,
+            deadlines: Default::default(),
+            refresh_state: Default::default(),
+            level_listeners: Default::default(),
+            token_memo: Default::default(),
+            metrics: Default::default(),
             force_sync: false,
         }
     }
 
+    /// Snapshots the cache's hit/miss/staleness counters and per-request-kind binder latency.
+    pub async fn metrics(&self) -> CacheMetrics {
+        CacheMetrics {
+            hits: self.metrics.hits.load(Ordering::Relaxed),
+            misses: self.metrics.misses.load(Ordering::Relaxed),
+            forced_syncs: self.metrics.forced_syncs.load(Ordering::Relaxed),
+            stale_serves: self.metrics.stale_serves.load(Ordering::Relaxed),
+            binder_latency: self.metrics.binder_latency.lock().await.clone(),
+        }
+    }
+
     /// Create from options
     pub async fn from_opts(common: &CommonOpt, auth: &AuthOpt) -> anyhow::Result<Self> {
-        let binder_client = common.to_binder_client().await;
+        let binder_clients = common.to_binder_clients().await;
         let mut dbpath = auth.credential_cache.clone();
         std::fs::create_dir_all(&dbpath)?;
         dbpath.push("ngcredentials.json");
@@ -63,22 +347,99 @@ impl ClientCache {
             &auth.password,
             common.binder_mizaru_free.clone(),
             common.binder_mizaru_plus.clone(),
-            binder_client.clone(),
+            binder_clients,
             database,
         );
         Ok(client_cache)
     }
 
+    /// Picks the next binder frontend to try, rotating round-robin across `binder_clients`.
+    fn next_binder_client(&self) -> Arc<dyn BinderClient> {
+        let idx = self.next_binder_client.fetch_add(1, Ordering::Relaxed) % self.binder_clients.len();
+        self.binder_clients[idx].clone()
+    }
+
+    /// Sends `req` to a binder frontend, retrying on timeout and on every `BinderError` except
+    /// `BinderError::WrongLevel` with jittered exponential backoff, rotating to the next
+    /// configured frontend on each attempt. `WrongLevel` is returned at once. Checks that callers
+    /// make on a successful response (e.g. signature verification) happen after this returns,
+    /// so they are never retried here.
+    /// `on_retry` runs before each retry's backoff sleep (e.g. so `get_bridges` can purge stale
+    /// bridge lists between attempts).
+    async fn request_with_retry(
+        &self,
+        req: BinderRequestData,
+        on_retry: impl Fn(),
+    ) -> anyhow::Result<BinderResponse> {
+        let kind = request_kind(&req);
+        let attempts = async {
+            let mut delay = self.retry_policy.base_delay;
+            for attempt in 1..=self.retry_policy.max_attempts {
+                let client = self.next_binder_client();
+                let last_attempt = attempt == self.retry_policy.max_attempts;
+                let started = Instant::now();
+                let outcome = timeout(client.request(req.clone())).await;
+                self.record_latency(kind, started.elapsed()).await;
+                match outcome {
+                    Ok(Ok(resp)) => return Ok(resp),
+                    Ok(Err(BinderError::WrongLevel)) => {
+                        return Err(BinderError::WrongLevel.into())
+                    }
+                    Ok(Err(e)) if last_attempt => return Err(e.into()),
+                    Ok(Err(e)) => {
+                        log::warn!(
+                            "binder request failed (attempt {}/{}): {:?}; retrying",
+                            attempt,
+                            self.retry_policy.max_attempts,
+                            e
+                        );
+                    }
+                    Err(e) if last_attempt => return Err(e),
+                    Err(_) => {
+                        log::warn!(
+                            "binder request timed out (attempt {}/{}); retrying",
+                            attempt,
+                            self.retry_policy.max_attempts
+                        );
+                    }
+                }
+                on_retry();
+                let jitter = rand::thread_rng().gen_range(0.5..1.5);
+                smol::Timer::after(delay.mul_f64(jitter).min(self.retry_policy.max_delay)).await;
+                delay = next_delay(delay, self.retry_policy.max_delay);
+            }
+            unreachable!("loop always returns on its last attempt")
+        };
+        // Bounds the *total* time this can take independent of `max_attempts *
+        // NETWORK_TIMEOUT`, so a caller with no stale fallback (like `get_auth_token`) still
+        // fails in bounded time during a hard binder outage.
+        attempts.timeout(self.retry_policy.overall_budget).await.unwrap_or_else(|| {
+            Err(anyhow::anyhow!(
+                "binder request for {} exceeded overall budget of {:?}",
+                kind,
+                self.retry_policy.overall_budget
+            ))
+        })
+    }
+
+    async fn record_latency(&self, kind: &'static str, elapsed: Duration) {
+        let mut hist = self.metrics.binder_latency.lock().await;
+        let stats = hist.entry(kind).or_default();
+        stats.count += 1;
+        stats.total += elapsed;
+        stats.max = stats.max.max(elapsed);
+    }
+
     fn get_cached_stale<T: DeserializeOwned + Clone + Debug>(&self, key: &str) -> Option<T> {
         if self.force_sync {
             return None;
         }
-        let key = self.to_key(key);
+        let expanded_key = self.to_key(key);
         let existing: Option<(T, u64)> = self
             .database
             .read()
-            .get(&key)
-            .map(|v| bincode::deserialize(v).unwrap());
+            .get(&expanded_key)
+            .and_then(|v| self.unseal_entry(key, v));
         existing.map(|v| v.0)
     }
 
@@ -86,6 +447,22 @@ impl ClientCache {
         format!("{}-{}", key, self.username)
     }
 
+    /// Wraps a value in a `CacheEnvelope` versioned per `key` and seals it with `self.cipher`
+    /// before it is written to `database`: a random 24-byte nonce followed by the
+    /// XChaCha20-Poly1305 ciphertext. `key` is the unexpanded cache key (e.g. `AUTH_TOKEN_KEY`),
+    /// not `to_key(key)`.
+    fn seal_entry<T: Serialize>(&self, key: &str, value: &T) -> Bytes {
+        seal_with(&self.cipher, key, value)
+    }
+
+    /// Opens a value sealed by `seal_entry` for the same `key`. Any failure — truncated data, a
+    /// wrong key because the password changed, a corrupt auth tag, or a `schema_version` that
+    /// doesn't match `key`'s current version — is treated as a cache miss (falling through to
+    /// the binder) rather than a panic.
+    fn unseal_entry<T: DeserializeOwned>(&self, key: &str, sealed: &[u8]) -> Option<T> {
+        unseal_with(&self.cipher, key, sealed)
+    }
+
     async fn get_cached_maybe_stale<T: Serialize + DeserializeOwned + Clone + std::fmt::Debug>(
         &self,
         key: &str,
@@ -97,6 +474,7 @@ impl ClientCache {
                 smol::Timer::after(STALE_TIMEOUT).await;
                 if let Some(val) = self.get_cached_stale(key) {
                     log::warn!("falling back to possibly stale value for {}", key);
+                    self.metrics.stale_serves.fetch_add(1, Ordering::Relaxed);
                     Ok(val)
                 } else {
                     log::warn!("no stale value available");
@@ -117,18 +495,26 @@ impl ClientCache {
             .database
             .read()
             .get(&expanded_key)
-            .map(|v| bincode::deserialize(v).unwrap());
-        if !self.force_sync {
-            if let Some((existing, timeout)) = existing {
-                if SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs()
-                    < timeout + ttl.as_secs()
-                {
-                    return Ok(existing);
-                }
+            .and_then(|v| self.unseal_entry(key, v));
+        if self.force_sync {
+            self.metrics.forced_syncs.fetch_add(1, Ordering::Relaxed);
+        } else if let Some((existing, timeout)) = existing {
+            self.deadlines
+                .lock()
+                .await
+                .insert(expanded_key.clone(), (timeout, ttl));
+            if SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                < timeout + ttl.as_secs()
+            {
+                self.metrics.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(existing);
             }
+            self.metrics.misses.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.metrics.misses.fetch_add(1, Ordering::Relaxed);
         }
         let deadline: SystemTime = SystemTime::now();
         let deadline = deadline
@@ -141,10 +527,12 @@ impl ClientCache {
         // save to disk
         self.database.write().insert(
             expanded_key.clone(),
-            bincode::serialize(&(fresh.clone(), deadline))
-                .unwrap()
-                .into(),
+            self.seal_entry(key, &(fresh.clone(), deadline)),
         );
+        self.deadlines
+            .lock()
+            .await
+            .insert(expanded_key.clone(), (deadline, ttl));
         log::trace!("about to return for {}!", expanded_key);
         Ok(fresh)
     }
@@ -152,32 +540,20 @@ impl ClientCache {
     /// Obtains a new token.
     pub async fn get_auth_token(&self) -> anyhow::Result<Token> {
         // This CANNOT be stale!
-        self.get_cached(
-            "cache.auth_token",
-            self.get_token_fresh(),
-            Duration::from_secs(86400),
-        )
-        .await
+        self.get_cached(AUTH_TOKEN_KEY, self.get_token_fresh(), AUTH_TOKEN_TTL)
+            .await
     }
 
     /// Gets a list of exits.
     pub async fn get_exits(&self) -> anyhow::Result<Vec<ExitDescriptor>> {
-        self.get_cached_maybe_stale(
-            "cache.exits",
-            self.get_exits_fresh(),
-            Duration::from_secs(3600),
-        )
-        .await
+        self.get_cached_maybe_stale(EXITS_KEY, self.get_exits_fresh(), EXITS_TTL)
+            .await
     }
 
     /// Gets a list of free exits.
     pub async fn get_free_exits(&self) -> anyhow::Result<Vec<ExitDescriptor>> {
-        self.get_cached_maybe_stale(
-            "cache.freeexits",
-            self.get_free_exits_fresh(),
-            Duration::from_secs(3600),
-        )
-        .await
+        self.get_cached_maybe_stale(FREE_EXITS_KEY, self.get_free_exits_fresh(), FREE_EXITS_TTL)
+            .await
     }
 
     /// Clears the bridge list. This should be called when a connection error happens, so that bad bridge lists are purged as fast as possible.
@@ -190,18 +566,25 @@ impl ClientCache {
     /// Gets a list of bridges.
     pub async fn get_bridges(&self, exit_hostname: &str) -> anyhow::Result<Vec<BridgeDescriptor>> {
         let tok = self.get_auth_token().await?;
-        let binder_client = self.binder_client.clone();
         let exit_hostname = exit_hostname.to_string();
         self.get_cached_maybe_stale(
             &format!("cache.bridges.{}", exit_hostname),
             async {
-                let res = timeout(binder_client.request(BinderRequestData::GetBridges {
-                    level: tok.level,
-                    unblinded_digest: tok.unblinded_digest,
-                    unblinded_signature: tok.unblinded_signature,
-                    exit_hostname,
-                }))
-                .await??;
+                // Purge between retries too, so a flaky attempt never leaves a half-stale
+                // bridge list lying around longer than it takes to retry.
+                let res = self
+                    .request_with_retry(
+                        BinderRequestData::GetBridges {
+                            level: tok.level.clone(),
+                            unblinded_digest: tok.unblinded_digest.clone(),
+                            unblinded_signature: tok.unblinded_signature.clone(),
+                            exit_hostname: exit_hostname.clone(),
+                        },
+                        || {
+                            let _ = self.purge_bridges(&exit_hostname);
+                        },
+                    )
+                    .await?;
                 if let BinderResponse::GetBridgesResp(bridges) = res {
                     Ok(bridges)
                 } else {
@@ -213,6 +596,143 @@ impl ClientCache {
         .await
     }
 
+    /// Subscribes to `LevelChange` notifications from the background refresher (see
+    /// `spawn_refresher`). Each call registers an independent channel, so every subscriber sees
+    /// every change.
+    pub async fn subscribe_level_changes(&self) -> smol::channel::Receiver<LevelChange> {
+        let (send, recv) = smol::channel::unbounded();
+        self.level_listeners.lock().await.push(send);
+        recv
+    }
+
+    async fn notify_level_change(&self, old_level: String, new_level: String) {
+        let change = LevelChange {
+            old_level,
+            new_level,
+        };
+        let mut listeners = self.level_listeners.lock().await;
+        listeners.retain(|send| send.try_send(change.clone()).is_ok() || !send.is_closed());
+    }
+
+    /// Starts a background task that proactively re-runs the `*_fresh` future for each entry in
+    /// `REFRESH_JOBS` once it crosses ~80% of its TTL, writing the result back through the same
+    /// `database` path as a normal `get_cached` call. This turns `get_auth_token`/`get_exits`/
+    /// `get_free_exits` into almost-always-instant reads instead of occasionally stalling on the
+    /// binder. Refreshes for the same key are coalesced, and binder errors back off
+    /// exponentially per key. The worker runs detached and holds its own `Arc` to `self`, so it
+    /// keeps running, and keeps the cache alive, for the lifetime of the process regardless of
+    /// whether the caller keeps anything around — there is no handle to hold onto or cancel.
+    pub fn spawn_refresher(self: &Arc<Self>) {
+        let this = self.clone();
+        smol::spawn(async move {
+            loop {
+                smol::Timer::after(REFRESH_POLL_INTERVAL).await;
+                // Each job runs on its own detached task rather than being awaited in sequence
+                // here: `maybe_refresh` can block for up to `retry_policy.overall_budget` when
+                // the binder is erroring, and a slow/failing auth-token refresh shouldn't delay
+                // the exits/free-exits checks behind it.
+                for (key, ttl) in REFRESH_JOBS {
+                    let this = this.clone();
+                    smol::spawn(async move { this.maybe_refresh(key, *ttl).await }).detach();
+                }
+            }
+        })
+        .detach();
+    }
+
+    async fn maybe_refresh(self: &Arc<Self>, key: &'static str, ttl: Duration) {
+        let expanded_key = self.to_key(key);
+        let due = match self.deadlines.lock().await.get(&expanded_key) {
+            Some((recorded_at, ttl)) => {
+                let now = SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+                let elapsed = now.saturating_sub(*recorded_at) as f64;
+                elapsed >= REFRESH_TRIGGER_FRAC * ttl.as_secs() as f64
+            }
+            // Nothing has populated this entry yet this run; let the first real `get_*` call do
+            // it lazily, then the refresher takes over from there.
+            None => false,
+        };
+        if !due {
+            return;
+        }
+        {
+            let mut state = self.refresh_state.lock().await;
+            let entry = state.entry(expanded_key.clone()).or_default();
+            if entry.in_flight {
+                return;
+            }
+            if let Some(retry_after) = entry.retry_after {
+                if Instant::now() < retry_after {
+                    return;
+                }
+            }
+            entry.in_flight = true;
+        }
+        let result = self.force_refresh(key, ttl).await;
+        let mut state = self.refresh_state.lock().await;
+        let entry = state.entry(expanded_key).or_default();
+        entry.in_flight = false;
+        match result {
+            Ok(()) => {
+                entry.backoff = Duration::ZERO;
+                entry.retry_after = None;
+            }
+            Err(e) => {
+                log::warn!("background refresh for {} failed: {:#}", key, e);
+                entry.backoff = (entry.backoff * 2).max(REFRESH_BACKOFF_BASE);
+                entry.backoff = entry.backoff.min(REFRESH_BACKOFF_MAX);
+                entry.retry_after = Some(Instant::now() + entry.backoff);
+            }
+        }
+    }
+
+    /// Unconditionally re-runs the `*_fresh` future for `key` and writes the result back,
+    /// bypassing the TTL check in `get_cached` (which would otherwise still consider a
+    /// not-yet-expired entry fresh). Used only by the background refresher.
+    async fn force_refresh(&self, key: &str, ttl: Duration) -> anyhow::Result<()> {
+        match key {
+            AUTH_TOKEN_KEY => {
+                let old_level = self.get_cached_stale::<Token>(key).map(|t| t.level);
+                let fresh = self.get_token_fresh().await?;
+                self.write_through(key, &fresh, ttl).await;
+                if let Some(old_level) = old_level {
+                    if old_level != fresh.level {
+                        self.notify_level_change(old_level, fresh.level).await;
+                    }
+                }
+            }
+            EXITS_KEY => {
+                let fresh = self.get_exits_fresh().await?;
+                self.write_through(key, &fresh, ttl).await;
+            }
+            FREE_EXITS_KEY => {
+                let fresh = self.get_free_exits_fresh().await?;
+                self.write_through(key, &fresh, ttl).await;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn write_through<T: Serialize + Clone>(&self, key: &str, fresh: &T, ttl: Duration) {
+        let expanded_key = self.to_key(key);
+        let deadline = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        self.database.write().insert(
+            expanded_key.clone(),
+            self.seal_entry(key, &(fresh.clone(), deadline)),
+        );
+        self.deadlines
+            .lock()
+            .await
+            .insert(expanded_key, (deadline, ttl));
+    }
+
     async fn get_token_fresh(&self) -> anyhow::Result<Token> {
         let digest: [u8; 32] = rand::thread_rng().gen();
         for level in &["plus", "free"] {
@@ -222,29 +742,35 @@ impl ClientCache {
                 &self.free_pk
             };
             let epoch = mizaru::time_to_epoch(SystemTime::now()) as u16;
-            let binder_client = self.binder_client.clone();
-            let subkey = timeout(binder_client.request(BinderRequestData::GetEpochKey {
-                level: level.to_string(),
-                epoch,
-            }))
-            .await??;
+            let subkey = self
+                .request_with_retry(
+                    BinderRequestData::GetEpochKey {
+                        level: level.to_string(),
+                        epoch,
+                    },
+                    || {},
+                )
+                .await?;
             if let BinderResponse::GetEpochKeyResp(subkey) = subkey {
                 // create FDH
                 let digest = blind::hash_message::<Sha256, _>(&subkey, &digest).unwrap();
                 // blinding
                 let (blinded_digest, unblinder) =
                     blind::blind(&mut rand::thread_rng(), &subkey, &digest);
-                let binder_client = self.binder_client.clone();
                 let username = self.username.clone();
                 let password = self.password.clone();
-                let resp = timeout(binder_client.request(BinderRequestData::Authenticate {
-                    username,
-                    password,
-                    level: level.to_string(),
-                    epoch,
-                    blinded_digest,
-                }))
-                .await?;
+                let resp = self
+                    .request_with_retry(
+                        BinderRequestData::Authenticate {
+                            username,
+                            password,
+                            level: level.to_string(),
+                            epoch,
+                            blinded_digest,
+                        },
+                        || {},
+                    )
+                    .await;
                 match resp {
                     Ok(BinderResponse::AuthenticateResp {
                         user_info,
@@ -262,9 +788,13 @@ impl ClientCache {
                             unblinded_signature,
                         });
                     }
-                    Err(BinderError::WrongLevel) => continue,
-                    Err(e) => return Err(e.into()),
-                    _ => continue,
+                    Ok(_) => continue,
+                    // `request_with_retry` never retries `WrongLevel`, so seeing it here just
+                    // means this level isn't the right one for this user; try the next.
+                    Err(e) if matches!(e.downcast_ref(), Some(BinderError::WrongLevel)) => {
+                        continue
+                    }
+                    Err(e) => return Err(e),
                 }
             }
         }
@@ -272,8 +802,9 @@ impl ClientCache {
     }
 
     async fn get_exits_fresh(&self) -> anyhow::Result<Vec<ExitDescriptor>> {
-        let binder_client = self.binder_client.clone();
-        let res = timeout(binder_client.request(BinderRequestData::GetExits)).await??;
+        let res = self
+            .request_with_retry(BinderRequestData::GetExits, || {})
+            .await?;
         match res {
             geph4_binder_transport::BinderResponse::GetExitsResp(exits) => Ok(exits),
             other => anyhow::bail!("unexpected response {:?}", other),
@@ -281,8 +812,9 @@ impl ClientCache {
     }
 
     async fn get_free_exits_fresh(&self) -> anyhow::Result<Vec<ExitDescriptor>> {
-        let binder_client = self.binder_client.clone();
-        let res = timeout(binder_client.request(BinderRequestData::GetFreeExits)).await??;
+        let res = self
+            .request_with_retry(BinderRequestData::GetFreeExits, || {})
+            .await?;
         match res {
             geph4_binder_transport::BinderResponse::GetExitsResp(exits) => Ok(exits),
             other => anyhow::bail!("unexpected response {:?}", other),
@@ -290,6 +822,237 @@ impl ClientCache {
     }
 }
 
+/// How long `serve_agent` keeps a decrypted `Token` memoized before dropping it from memory.
+#[cfg(unix)]
+const AGENT_IDLE_LOCK: Duration = Duration::from_secs(15 * 60);
+
+/// How often the idle-lock task in `serve_agent` checks whether `AGENT_IDLE_LOCK` has elapsed.
+/// Much shorter than `AGENT_IDLE_LOCK` itself, so a memoized token stays resident for at most
+/// `AGENT_IDLE_LOCK + AGENT_IDLE_LOCK_POLL`, not up to 2x `AGENT_IDLE_LOCK` from checking only
+/// once per `AGENT_IDLE_LOCK`.
+#[cfg(unix)]
+const AGENT_IDLE_LOCK_POLL: Duration = Duration::from_secs(30);
+
+/// Largest frame `read_framed` will allocate a buffer for. The protocol's largest legitimate
+/// message is a `Token`/exit-list response, which bincode-encodes to well under this; anything
+/// bigger is a malformed or malicious length prefix, not a real request/response.
+#[cfg(unix)]
+const MAX_FRAME_LEN: usize = 1024 * 1024;
+
+/// Credential-agent transport: deliberately Unix-domain-socket only for now. The request this
+/// implements also asked for a Windows named-pipe transport, but geph4's async runtime (`smol`)
+/// has no named-pipe support to build on, so that half is intentionally out of scope here rather
+/// than silently missing — `serve_agent`/`AgentClientCache` simply don't exist on non-Unix
+/// targets until a named-pipe transport is added as a follow-up.
+#[cfg(unix)]
+impl ClientCache {
+    /// Serves this `ClientCache` to other local processes over `listener`, so that multiple geph
+    /// processes on one machine can share a single authenticated session instead of each
+    /// re-running the blind-signature dance in `get_token_fresh`. Each connection reads one
+    /// length-prefixed (`AgentRequest`, bincode-encoded) request at a time and writes back a
+    /// matching `AgentResponse`, framed the same way; see `AgentClientCache` for the other end.
+    pub async fn serve_agent(self: Arc<Self>, listener: smol::net::unix::UnixListener) -> anyhow::Result<()> {
+        let this = self.clone();
+        smol::spawn(async move {
+            loop {
+                smol::Timer::after(AGENT_IDLE_LOCK_POLL).await;
+                let mut memo = this.token_memo.lock().await;
+                if matches!(&*memo, Some((_, seen)) if seen.elapsed() >= AGENT_IDLE_LOCK) {
+                    *memo = None;
+                }
+            }
+        })
+        .detach();
+        loop {
+            let (conn, _) = listener.accept().await?;
+            let this = self.clone();
+            smol::spawn(async move {
+                if let Err(e) = this.handle_agent_conn(conn).await {
+                    log::debug!("credential agent connection closed: {:?}", e);
+                }
+            })
+            .detach();
+        }
+    }
+
+    async fn handle_agent_conn(&self, mut conn: smol::net::unix::UnixStream) -> anyhow::Result<()> {
+        loop {
+            let req: AgentRequest = match read_framed(&mut conn).await {
+                Ok(req) => req,
+                Err(_) => return Ok(()), // peer hung up
+            };
+            let resp = match req {
+                AgentRequest::GetAuthToken => match self.agent_get_auth_token().await {
+                    Ok(tok) => AgentResponse::Token(tok),
+                    Err(e) => AgentResponse::Err(e.to_string()),
+                },
+                AgentRequest::GetExits => match self.get_exits().await {
+                    Ok(exits) => AgentResponse::Exits(exits),
+                    Err(e) => AgentResponse::Err(e.to_string()),
+                },
+                AgentRequest::GetFreeExits => match self.get_free_exits().await {
+                    Ok(exits) => AgentResponse::Exits(exits),
+                    Err(e) => AgentResponse::Err(e.to_string()),
+                },
+                AgentRequest::GetBridges { exit_hostname } => {
+                    match self.get_bridges(&exit_hostname).await {
+                        Ok(bridges) => AgentResponse::Bridges(bridges),
+                        Err(e) => AgentResponse::Err(e.to_string()),
+                    }
+                }
+                AgentRequest::PurgeBridges { exit_hostname } => {
+                    match self.purge_bridges(&exit_hostname) {
+                        Ok(()) => AgentResponse::Purged,
+                        Err(e) => AgentResponse::Err(e.to_string()),
+                    }
+                }
+            };
+            write_framed(&mut conn, &resp).await?;
+        }
+    }
+
+    async fn agent_get_auth_token(&self) -> anyhow::Result<Token> {
+        if let Some((tok, seen)) = &*self.token_memo.lock().await {
+            if seen.elapsed() < AGENT_IDLE_LOCK {
+                return Ok(tok.clone());
+            }
+        }
+        let tok = self.get_auth_token().await?;
+        *self.token_memo.lock().await = Some((tok.clone(), Instant::now()));
+        Ok(tok)
+    }
+}
+
+/// Request/response protocol spoken between `ClientCache::serve_agent` and `AgentClientCache`.
+/// Mirrors `ClientCache`'s public accessors.
+#[cfg(unix)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum AgentRequest {
+    GetAuthToken,
+    GetExits,
+    GetFreeExits,
+    GetBridges { exit_hostname: String },
+    PurgeBridges { exit_hostname: String },
+}
+
+#[cfg(unix)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum AgentResponse {
+    Token(Token),
+    Exits(Vec<ExitDescriptor>),
+    Bridges(Vec<BridgeDescriptor>),
+    Purged,
+    Err(String),
+}
+
+/// A thin client of `ClientCache::serve_agent`: instead of holding credentials and talking to
+/// the binder directly, every call round-trips a request over a unix socket to a `ClientCache`
+/// running in another (or the same) process. Lets several geph processes on one machine share a
+/// single authenticated session.
+#[cfg(unix)]
+pub struct AgentClientCache {
+    socket_path: PathBuf,
+}
+
+#[cfg(unix)]
+impl AgentClientCache {
+    /// Creates a thin client that connects to `socket_path` on every call.
+    pub fn new(socket_path: impl Into<PathBuf>) -> Self {
+        AgentClientCache {
+            socket_path: socket_path.into(),
+        }
+    }
+
+    async fn roundtrip(&self, req: AgentRequest) -> anyhow::Result<AgentResponse> {
+        let mut conn = smol::net::unix::UnixStream::connect(&self.socket_path).await?;
+        write_framed(&mut conn, &req).await?;
+        read_framed(&mut conn).await
+    }
+
+    /// Obtains a (possibly cached) auth token from the agent.
+    pub async fn get_auth_token(&self) -> anyhow::Result<Token> {
+        match self.roundtrip(AgentRequest::GetAuthToken).await? {
+            AgentResponse::Token(tok) => Ok(tok),
+            AgentResponse::Err(e) => anyhow::bail!(e),
+            other => anyhow::bail!("unexpected agent response {:?}", other),
+        }
+    }
+
+    /// Gets a list of exits from the agent.
+    pub async fn get_exits(&self) -> anyhow::Result<Vec<ExitDescriptor>> {
+        match self.roundtrip(AgentRequest::GetExits).await? {
+            AgentResponse::Exits(exits) => Ok(exits),
+            AgentResponse::Err(e) => anyhow::bail!(e),
+            other => anyhow::bail!("unexpected agent response {:?}", other),
+        }
+    }
+
+    /// Gets a list of free exits from the agent.
+    pub async fn get_free_exits(&self) -> anyhow::Result<Vec<ExitDescriptor>> {
+        match self.roundtrip(AgentRequest::GetFreeExits).await? {
+            AgentResponse::Exits(exits) => Ok(exits),
+            AgentResponse::Err(e) => anyhow::bail!(e),
+            other => anyhow::bail!("unexpected agent response {:?}", other),
+        }
+    }
+
+    /// Gets a list of bridges from the agent.
+    pub async fn get_bridges(&self, exit_hostname: &str) -> anyhow::Result<Vec<BridgeDescriptor>> {
+        match self
+            .roundtrip(AgentRequest::GetBridges {
+                exit_hostname: exit_hostname.to_string(),
+            })
+            .await?
+        {
+            AgentResponse::Bridges(bridges) => Ok(bridges),
+            AgentResponse::Err(e) => anyhow::bail!(e),
+            other => anyhow::bail!("unexpected agent response {:?}", other),
+        }
+    }
+
+    /// Asks the agent to purge its cached bridge list for `exit_hostname`.
+    pub async fn purge_bridges(&self, exit_hostname: &str) -> anyhow::Result<()> {
+        match self
+            .roundtrip(AgentRequest::PurgeBridges {
+                exit_hostname: exit_hostname.to_string(),
+            })
+            .await?
+        {
+            AgentResponse::Purged => Ok(()),
+            AgentResponse::Err(e) => anyhow::bail!(e),
+            other => anyhow::bail!("unexpected agent response {:?}", other),
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn read_framed<T: DeserializeOwned>(
+    conn: &mut (impl smol::io::AsyncRead + Unpin),
+) -> anyhow::Result<T> {
+    use smol::io::AsyncReadExt;
+    let mut len_buf = [0u8; 4];
+    conn.read_exact(&mut len_buf).await?;
+    let len = u32::from_be_bytes(len_buf) as usize;
+    if len > MAX_FRAME_LEN {
+        anyhow::bail!("agent frame of {} bytes exceeds MAX_FRAME_LEN ({})", len, MAX_FRAME_LEN);
+    }
+    let mut buf = vec![0u8; len];
+    conn.read_exact(&mut buf).await?;
+    Ok(bincode::deserialize(&buf)?)
+}
+
+#[cfg(unix)]
+async fn write_framed<T: Serialize>(
+    conn: &mut (impl smol::io::AsyncWrite + Unpin),
+    value: &T,
+) -> anyhow::Result<()> {
+    use smol::io::AsyncWriteExt;
+    let buf = bincode::serialize(value)?;
+    conn.write_all(&(buf.len() as u32).to_be_bytes()).await?;
+    conn.write_all(&buf).await?;
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Token {
     pub user_info: geph4_binder_transport::UserInfo,
@@ -304,3 +1067,113 @@ async fn timeout<T, F: Future<Output = T>>(fut: F) -> anyhow::Result<T> {
         .await
         .ok_or_else(|| anyhow::anyhow!("timeout"))
 }
+
+/// Labels a binder request for the per-kind latency stats in `CacheMetrics` (count, total and
+/// max only; no bucketed distribution is kept).
+fn request_kind(req: &BinderRequestData) -> &'static str {
+    match req {
+        BinderRequestData::GetEpochKey { .. } => "get_epoch_key",
+        BinderRequestData::Authenticate { .. } => "authenticate",
+        BinderRequestData::GetExits => "get_exits",
+        BinderRequestData::GetFreeExits => "get_free_exits",
+        BinderRequestData::GetBridges { .. } => "get_bridges",
+        #[allow(unreachable_patterns)]
+        _ => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_cipher(password: &str) -> XChaCha20Poly1305 {
+        let salt = b"unit-test-salt-unit-test-salt!!";
+        let key = derive_cache_key(password, salt);
+        XChaCha20Poly1305::new(Key::from_slice(&key))
+    }
+
+    #[test]
+    fn seal_unseal_round_trip() {
+        let cipher = test_cipher("hunter2");
+        let sealed = seal_with(&cipher, AUTH_TOKEN_KEY, &"round trip me".to_string());
+        let opened: Option<String> = unseal_with(&cipher, AUTH_TOKEN_KEY, &sealed);
+        assert_eq!(opened, Some("round trip me".to_string()));
+    }
+
+    #[test]
+    fn unseal_rejects_wrong_key() {
+        let sealed = seal_with(&test_cipher("hunter2"), AUTH_TOKEN_KEY, &"secret".to_string());
+        let opened: Option<String> =
+            unseal_with(&test_cipher("not-hunter2"), AUTH_TOKEN_KEY, &sealed);
+        assert_eq!(opened, None);
+    }
+
+    #[test]
+    fn unseal_rejects_schema_version_mismatch() {
+        let cipher = test_cipher("hunter2");
+        let envelope = CacheEnvelope {
+            schema_version: schema_version_for(AUTH_TOKEN_KEY) + 1,
+            payload: bincode::serialize(&"secret".to_string()).unwrap().into(),
+        };
+        let plaintext = bincode::serialize(&envelope).unwrap();
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let mut ciphertext = cipher.encrypt(&nonce, plaintext.as_ref()).unwrap();
+        let mut sealed = nonce.to_vec();
+        sealed.append(&mut ciphertext);
+
+        let opened: Option<String> = unseal_with(&cipher, AUTH_TOKEN_KEY, &sealed);
+        assert_eq!(opened, None);
+    }
+
+    #[test]
+    fn unseal_rejects_truncated_frame() {
+        let cipher = test_cipher("hunter2");
+        let sealed = seal_with(&cipher, AUTH_TOKEN_KEY, &"secret".to_string());
+        let opened: Option<String> = unseal_with(&cipher, AUTH_TOKEN_KEY, &sealed[..sealed.len() - 1]);
+        assert_eq!(opened, None);
+
+        let opened_empty: Option<String> = unseal_with(&cipher, AUTH_TOKEN_KEY, &[]);
+        assert_eq!(opened_empty, None);
+    }
+
+    #[test]
+    fn schema_versions_evolve_independently_per_key() {
+        // Sealing a value under EXITS_KEY and reading it back under AUTH_TOKEN_KEY's schema
+        // version must not spuriously succeed or corrupt unrelated keys' version tracking.
+        let cipher = test_cipher("hunter2");
+        let sealed = seal_with(&cipher, EXITS_KEY, &"exits payload".to_string());
+        let wrong_key_read: Option<String> = unseal_with(&cipher, AUTH_TOKEN_KEY, &sealed);
+        assert_eq!(
+            wrong_key_read, None,
+            "a schema version bump for one cache key must not affect another key's entries"
+        );
+        let right_key_read: Option<String> = unseal_with(&cipher, EXITS_KEY, &sealed);
+        assert_eq!(right_key_read, Some("exits payload".to_string()));
+    }
+
+    #[test]
+    fn backoff_delay_doubles_and_caps() {
+        let max = Duration::from_secs(30);
+        let mut delay = Duration::from_millis(500);
+        for _ in 0..3 {
+            delay = next_delay(delay, max);
+        }
+        assert_eq!(delay, Duration::from_millis(4000));
+
+        // Keeps doubling past the cap without overflowing or exceeding `max`.
+        let mut delay = Duration::from_secs(20);
+        for _ in 0..5 {
+            delay = next_delay(delay, max);
+        }
+        assert_eq!(delay, max);
+    }
+
+    #[test]
+    fn request_kind_labels_known_variants() {
+        assert_eq!(request_kind(&BinderRequestData::GetExits), "get_exits");
+        assert_eq!(
+            request_kind(&BinderRequestData::GetFreeExits),
+            "get_free_exits"
+        );
+    }
+}